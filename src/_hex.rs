@@ -0,0 +1,84 @@
+use ser::SyncSerialize;
+
+// NOTE: these adapters are not part of the `ser` module. This file is
+// `include!`d at the crate root next to the other `include!`d units, so the
+// types are defined there directly as `slog::Hex` / `slog::HexSummary`, where
+// the crate also surfaces `Serialize` et al. from `ser`.
+
+/// Serialization adapter rendering a byte slice as lowercase hex
+///
+/// Two hex digits are emitted per byte, with no separators, and without any
+/// intermediate allocation - the bytes are streamed straight into the record's
+/// formatter. Use it (or the `log_bytes!` macro) to keep protocol and crypto
+/// payloads legible instead of falling back to a decimal `{:?}` array.
+///
+/// ```
+/// #[macro_use]
+/// extern crate slog;
+///
+/// fn main() {
+///     let drain = slog::Discard;
+///     let root = slog::Logger::root(drain, o!());
+///     info!(root, "nonce"; "bytes" => slog::Hex(&[0x00, 0xffu8]));
+/// }
+/// ```
+pub struct Hex<'a>(pub &'a [u8]);
+
+/// Serialization adapter rendering a long byte slice as an elided hex summary
+///
+/// Only the first and last `n` bytes are rendered as hex, joined by `..` and
+/// followed by the total length of the slice (eg. `dead..beef (8 bytes)` for
+/// an 8-byte slice with `n == 2`), so large binary
+/// payloads stay readable in terminal drains. Slices of `2 * n` bytes or fewer
+/// are rendered in full, exactly like `Hex`. Like `Hex`, it allocates nothing.
+pub struct HexSummary<'a>(pub &'a [u8], pub usize);
+
+impl<'a> fmt::Display for Hex<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.0 {
+            try!(write!(f, "{:02x}", byte));
+        }
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Display for HexSummary<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let HexSummary(bytes, n) = *self;
+        if bytes.len() <= n * 2 {
+            write!(f, "{}", Hex(bytes))
+        } else {
+            write!(f,
+                   "{}..{} ({} bytes)",
+                   Hex(&bytes[..n]),
+                   Hex(&bytes[bytes.len() - n..]),
+                   bytes.len())
+        }
+    }
+}
+
+impl<'a> Serialize for Hex<'a> {
+    fn serialize(&self,
+                 _record: &Record,
+                 key: &'static str,
+                 serializer: &mut Serializer)
+                 -> result::Result<(), ser::Error> {
+        serializer.emit_arguments(key, &format_args!("{}", self))
+    }
+}
+
+impl<'a> Serialize for HexSummary<'a> {
+    fn serialize(&self,
+                 _record: &Record,
+                 key: &'static str,
+                 serializer: &mut Serializer)
+                 -> result::Result<(), ser::Error> {
+        serializer.emit_arguments(key, &format_args!("{}", self))
+    }
+}
+
+// `SyncSerialize` requires `'static`, so it is only available when the wrapped
+// slice is itself `'static`; borrowed wrappers are usable in the `BorrowedKeyValue`
+// position via `Serialize`.
+impl SyncSerialize for Hex<'static> {}
+impl SyncSerialize for HexSummary<'static> {}
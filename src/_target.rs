@@ -0,0 +1,72 @@
+impl<'a> Record<'a> {
+    /// Get the logical target of the record
+    ///
+    /// The target defaults to the source `module_path!()`, but can be
+    /// overridden at the logging call site (see the `log!` macro) to tag a
+    /// record with a subsystem name. Drains may use it for user-configured
+    /// routing and filtering.
+    pub fn target(&self) -> &str {
+        self.rs.target
+    }
+}
+
+/// Filtering `Drain` matching a record's target against prefix rules
+///
+/// Each rule associates a target prefix (in `module_path!()` style, eg.
+/// `"net::handshake"`) with the most verbose `FilterLevel` that is still
+/// admitted for that subsystem. A record is passed to the wrapped drain only
+/// if its level is at least as severe as the level configured for the longest
+/// matching prefix; records whose target matches no rule are admitted.
+///
+/// This lets whole subsystems be routed or suppressed without touching every
+/// call site.
+pub struct TargetFilter<D: Drain> {
+    rules: Vec<(&'static str, FilterLevel)>,
+    drain: D,
+}
+
+impl<D: Drain> TargetFilter<D> {
+    /// Create a new `TargetFilter`
+    ///
+    /// `rules` is a set of `(target-prefix, level)` pairs. Order does not
+    /// matter - the longest matching prefix always wins.
+    pub fn new(drain: D, rules: Vec<(&'static str, FilterLevel)>) -> Self {
+        TargetFilter {
+            rules: rules,
+            drain: drain,
+        }
+    }
+
+    /// Level admitted for a given target, or `None` if no rule matches
+    fn level_for(&self, target: &str) -> Option<FilterLevel> {
+        let mut best: Option<(&'static str, FilterLevel)> = None;
+        for &(prefix, level) in &self.rules {
+            if target == prefix ||
+               (target.starts_with(prefix) && target[prefix.len()..].starts_with("::")) {
+                match best {
+                    Some((p, _)) if p.len() >= prefix.len() => {}
+                    _ => best = Some((prefix, level)),
+                }
+            }
+        }
+        best.map(|(_, level)| level)
+    }
+}
+
+impl<D: Drain> Drain for TargetFilter<D> {
+    type Error = D::Error;
+    fn log(&self,
+           record: &Record,
+           values: &OwnedKeyValueList)
+           -> result::Result<(), Self::Error> {
+        let admit = match self.level_for(record.target()) {
+            Some(level) => record.level().as_usize() <= level.as_usize(),
+            None => true,
+        };
+        if admit {
+            self.drain.log(record, values)
+        } else {
+            Ok(())
+        }
+    }
+}
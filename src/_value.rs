@@ -0,0 +1,139 @@
+/// Owned, typed snapshot of a serialized value
+///
+/// `Serialize` implementations funnel values through the `Serializer`
+/// `emit_*` methods, but buffering drains (async) and machine-readable drains
+/// (JSON) need to carry the type information across a thread boundary rather
+/// than reconstruct it from a pre-rendered `format_args!`. `Value` captures the
+/// emitted value losslessly for the common scalar types, falling back to a
+/// rendered `Fmt` string for everything else (eg. `emit_arguments`).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// Boolean
+    Bool(bool),
+    /// Signed integer
+    I64(i64),
+    /// Unsigned integer
+    U64(u64),
+    /// Floating point
+    F64(f64),
+    /// String
+    Str(String),
+    /// Character
+    Char(char),
+    /// Unit (`()`)
+    Unit,
+    /// Absence of a value
+    None,
+    /// Pre-rendered fallback for types without a native representation
+    Fmt(String),
+}
+
+/// `Serializer` that records the emitted value into a `Value`
+///
+/// A single `Serialize::serialize` call emits exactly one value, so the
+/// capturer keeps the last value it was handed.
+struct ValueCapture {
+    value: Value,
+}
+
+impl ValueCapture {
+    fn new() -> Self {
+        ValueCapture { value: Value::None }
+    }
+}
+
+impl Serializer for ValueCapture {
+    fn emit_none(&mut self, _key: &str) -> result::Result<(), ser::Error> {
+        self.value = Value::None;
+        Ok(())
+    }
+    fn emit_unit(&mut self, _key: &str) -> result::Result<(), ser::Error> {
+        self.value = Value::Unit;
+        Ok(())
+    }
+    fn emit_bool(&mut self, _key: &str, val: bool) -> result::Result<(), ser::Error> {
+        self.value = Value::Bool(val);
+        Ok(())
+    }
+    fn emit_char(&mut self, _key: &str, val: char) -> result::Result<(), ser::Error> {
+        self.value = Value::Char(val);
+        Ok(())
+    }
+    fn emit_usize(&mut self, _key: &str, val: usize) -> result::Result<(), ser::Error> {
+        self.value = Value::U64(val as u64);
+        Ok(())
+    }
+    fn emit_isize(&mut self, _key: &str, val: isize) -> result::Result<(), ser::Error> {
+        self.value = Value::I64(val as i64);
+        Ok(())
+    }
+    fn emit_u8(&mut self, _key: &str, val: u8) -> result::Result<(), ser::Error> {
+        self.value = Value::U64(val as u64);
+        Ok(())
+    }
+    fn emit_i8(&mut self, _key: &str, val: i8) -> result::Result<(), ser::Error> {
+        self.value = Value::I64(val as i64);
+        Ok(())
+    }
+    fn emit_u16(&mut self, _key: &str, val: u16) -> result::Result<(), ser::Error> {
+        self.value = Value::U64(val as u64);
+        Ok(())
+    }
+    fn emit_i16(&mut self, _key: &str, val: i16) -> result::Result<(), ser::Error> {
+        self.value = Value::I64(val as i64);
+        Ok(())
+    }
+    fn emit_u32(&mut self, _key: &str, val: u32) -> result::Result<(), ser::Error> {
+        self.value = Value::U64(val as u64);
+        Ok(())
+    }
+    fn emit_i32(&mut self, _key: &str, val: i32) -> result::Result<(), ser::Error> {
+        self.value = Value::I64(val as i64);
+        Ok(())
+    }
+    fn emit_u64(&mut self, _key: &str, val: u64) -> result::Result<(), ser::Error> {
+        self.value = Value::U64(val);
+        Ok(())
+    }
+    fn emit_i64(&mut self, _key: &str, val: i64) -> result::Result<(), ser::Error> {
+        self.value = Value::I64(val);
+        Ok(())
+    }
+    fn emit_f32(&mut self, _key: &str, val: f32) -> result::Result<(), ser::Error> {
+        self.value = Value::F64(val as f64);
+        Ok(())
+    }
+    fn emit_f64(&mut self, _key: &str, val: f64) -> result::Result<(), ser::Error> {
+        self.value = Value::F64(val);
+        Ok(())
+    }
+    fn emit_str(&mut self, _key: &str, val: &str) -> result::Result<(), ser::Error> {
+        self.value = Value::Str(val.into());
+        Ok(())
+    }
+    fn emit_arguments(&mut self,
+                      _key: &str,
+                      val: &fmt::Arguments)
+                      -> result::Result<(), ser::Error> {
+        let mut s = String::new();
+        // writing to a `String` is infallible
+        let _ = s.write_fmt(*val);
+        self.value = Value::Fmt(s);
+        Ok(())
+    }
+}
+
+/// Extension trait adding typed value capture to any `Serialize`
+///
+/// Implemented for every `Serialize` on top of the existing `emit_*` methods,
+/// so custom `Serialize` impls need no changes.
+pub trait Capture: Serialize {
+    /// Drive a capturing `Serializer` and return the emitted `Value`
+    fn capture(&self, record: &Record, key: &'static str) -> result::Result<Value, ser::Error> {
+        let mut capture = ValueCapture::new();
+        try!(self.serialize(record, key, &mut capture));
+        Ok(capture.value)
+    }
+}
+
+impl<T: Serialize + ?Sized> Capture for T {}
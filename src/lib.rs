@@ -103,7 +103,9 @@ extern crate collections;
 
 use core::str::FromStr;
 use core::fmt;
+use core::fmt::Write;
 use core::result;
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 #[cfg(feature = "std")]
 use std::sync::Arc;
@@ -125,6 +127,11 @@ use std::boxed::Box;
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
 
+#[cfg(feature = "std")]
+use std::string::String;
+#[cfg(not(feature = "std"))]
+use collections::string::String;
+
 /// Convenience function for building `&[OwnedKeyValue]`
 ///
 /// ```
@@ -148,6 +155,30 @@ macro_rules! o(
     };
 );
 
+/// Serialize a byte slice as lowercase hex
+///
+/// Convenience wrapper around `Hex`, usable directly in the value position of
+/// a logging statement so raw `&[u8]` payloads render as hex instead of a
+/// noisy decimal `{:?}` array.
+///
+/// ```
+/// #[macro_use]
+/// extern crate slog;
+///
+/// fn main() {
+///     let drain = slog::Discard;
+///     let root = slog::Logger::root(drain, o!());
+///     let buf = [0xde, 0xad, 0xbe, 0xefu8];
+///     info!(root, "received frame"; "payload" => log_bytes!(buf));
+/// }
+/// ```
+#[macro_export]
+macro_rules! log_bytes(
+    ($slice:expr) => {
+        $crate::Hex(&$slice[..])
+    };
+);
+
 /// Log message of a given level
 ///
 /// Use wrappers `error!`, `warn!` etc. instead
@@ -211,11 +242,87 @@ macro_rules! o(
 ///     info!(root, "formatted: {}", 1);
 /// }
 /// ```
+///
+/// A logical target can be attached to a record by prefixing the arguments
+/// with `#"target", `. The target is a free-form string, independent of the
+/// source `module_path!()`, that drains (eg. `TargetFilter`) can use for
+/// user-configured routing and filtering:
+///
+/// ```
+/// #[macro_use]
+/// extern crate slog;
+///
+/// fn main() {
+///     let drain = slog::Discard;
+///     let root = slog::Logger::root(drain, o!("key1" => "value1"));
+///     info!(root, #"net::handshake", "peer connected"; "addr" => "1.2.3.4");
+/// }
+/// ```
 
 #[macro_export]
 macro_rules! log(
+    ($lvl:expr, $l:expr, #$tgt:expr, $($k:expr => $v:expr),*; $($args:tt)+ ) => {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
+            // prevent generating big `Record` over and over
+            static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
+                level: $lvl,
+                file: file!(),
+                line: line!(),
+                column: column!(),
+                function: "",
+                module: module_path!(),
+                target: $tgt,
+            };
+            $l.log(&$crate::Record::new(&RS, format_args!($($args)+), &[$(($k, &$v)),*]))
+        }
+    };
+    ($lvl:expr, $l:expr, #$tgt:expr, $($args:tt),+) => {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
+            // prevent generating big `Record` over and over
+            static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
+                level: $lvl,
+                file: file!(),
+                line: line!(),
+                column: column!(),
+                function: "",
+                module: module_path!(),
+                target: $tgt,
+            };
+            $l.log(&$crate::Record::new(&RS, format_args!($($args),+), &[]))
+        }
+    };
+    ($lvl:expr, $l:expr, #$tgt:expr, $msg:expr) => {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
+            // prevent generating big `Record` over and over
+            static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
+                level: $lvl,
+                file: file!(),
+                line: line!(),
+                column: column!(),
+                function: "",
+                module: module_path!(),
+                target: $tgt,
+            };
+            $l.log(&$crate::Record::new(&RS, format_args!("{}", $msg), &[]))
+        }
+    };
+    ($lvl:expr, $l:expr, #$tgt:expr, $msg:expr; $($k:expr => $v:expr),*) => {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
+            // prevent generating big `Record` over and over
+            static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
+                level: $lvl,
+                file: file!(),
+                line: line!(),
+                column: column!(),
+                function: "",
+                module: module_path!(),
+                target: $tgt,
+            };
+            $l.log(&$crate::Record::new(&RS, format_args!("{}", $msg), &[$(($k, &$v)),*]))
+        }
+    };
     ($lvl:expr, $l:expr, $($k:expr => $v:expr),*; $($args:tt)+ ) => {
-        if $lvl.as_usize() <= $crate::__slog_static_max_level().as_usize() {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
             // prevent generating big `Record` over and over
             static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
                 level: $lvl,
@@ -230,7 +337,7 @@ macro_rules! log(
         }
     };
     ($lvl:expr, $l:expr, $($args:tt),+) => {
-        if $lvl.as_usize() <= $crate::__slog_static_max_level().as_usize() {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
             // prevent generating big `Record` over and over
             static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
                 level: $lvl,
@@ -245,7 +352,7 @@ macro_rules! log(
         }
     };
     ($lvl:expr, $l:expr, $msg:expr) => {
-        if $lvl.as_usize() <= $crate::__slog_static_max_level().as_usize() {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
             // prevent generating big `Record` over and over
             static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
                 level: $lvl,
@@ -260,7 +367,7 @@ macro_rules! log(
         }
     };
     ($lvl:expr, $l:expr, $msg:expr; $($k:expr => $v:expr),*) => {
-        if $lvl.as_usize() <= $crate::__slog_static_max_level().as_usize() {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
             // prevent generating big `Record` over and over
             static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
                 level: $lvl,
@@ -284,8 +391,68 @@ macro_rules! log(
 /// See `log` for documentation.
 #[macro_export]
 macro_rules! slog_log(
+    ($lvl:expr, $l:expr, #$tgt:expr, $($k:expr => $v:expr),*; $($args:tt)+ ) => {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
+            // prevent generating big `Record` over and over
+            static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
+                level: $lvl,
+                file: file!(),
+                line: line!(),
+                column: column!(),
+                function: "",
+                module: module_path!(),
+                target: $tgt,
+            };
+            $l.log(&$crate::Record::new(&RS, format_args!($($args)+), &[$(($k, &$v)),*]))
+        }
+    };
+    ($lvl:expr, $l:expr, #$tgt:expr, $($args:tt),+) => {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
+            // prevent generating big `Record` over and over
+            static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
+                level: $lvl,
+                file: file!(),
+                line: line!(),
+                column: column!(),
+                function: "",
+                module: module_path!(),
+                target: $tgt,
+            };
+            $l.log(&$crate::Record::new(&RS, format_args!($($args),+), &[]))
+        }
+    };
+    ($lvl:expr, $l:expr, #$tgt:expr, $msg:expr) => {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
+            // prevent generating big `Record` over and over
+            static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
+                level: $lvl,
+                file: file!(),
+                line: line!(),
+                column: column!(),
+                function: "",
+                module: module_path!(),
+                target: $tgt,
+            };
+            $l.log(&$crate::Record::new(&RS, format_args!("{}", $msg), &[]))
+        }
+    };
+    ($lvl:expr, $l:expr, #$tgt:expr, $msg:expr; $($k:expr => $v:expr),*) => {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
+            // prevent generating big `Record` over and over
+            static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
+                level: $lvl,
+                file: file!(),
+                line: line!(),
+                column: column!(),
+                function: "",
+                module: module_path!(),
+                target: $tgt,
+            };
+            $l.log(&$crate::Record::new(&RS, format_args!("{}", $msg), &[$(($k, &$v)),*]))
+        }
+    };
     ($lvl:expr, $l:expr, $($k:expr => $v:expr),*; $($args:tt)+ ) => {
-        if $lvl.as_usize() <= $crate::__slog_static_max_level().as_usize() {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
             // prevent generating big `Record` over and over
             static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
                 level: $lvl,
@@ -300,7 +467,7 @@ macro_rules! slog_log(
         }
     };
     ($lvl:expr, $l:expr, $($args:tt),+) => {
-        if $lvl.as_usize() <= $crate::__slog_static_max_level().as_usize() {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
             // prevent generating big `Record` over and over
             static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
                 level: $lvl,
@@ -315,7 +482,7 @@ macro_rules! slog_log(
         }
     };
     ($lvl:expr, $l:expr, $msg:expr) => {
-        if $lvl.as_usize() <= $crate::__slog_static_max_level().as_usize() {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
             // prevent generating big `Record` over and over
             static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
                 level: $lvl,
@@ -330,7 +497,7 @@ macro_rules! slog_log(
         }
     };
     ($lvl:expr, $l:expr, $msg:expr; $($k:expr => $v:expr),*) => {
-        if $lvl.as_usize() <= $crate::__slog_static_max_level().as_usize() {
+        if $lvl.as_usize() <= $crate::__slog_max_level().as_usize() {
             // prevent generating big `Record` over and over
             static RS : $crate::RecordStatic<'static> = $crate::RecordStatic {
                 level: $lvl,
@@ -494,6 +661,9 @@ pub use ser::{PushLazy, ValueSerializer, Serializer, Serialize};
 include!("_level.rs");
 include!("_logger.rs");
 include!("_drain.rs");
+include!("_target.rs");
+include!("_hex.rs");
+include!("_value.rs");
 
 /// Key value pair that can be part of a logging record
 pub type BorrowedKeyValue<'a> = (&'static str, &'a ser::Serialize);
@@ -543,6 +713,21 @@ impl OwnedKeyValueList {
     pub fn iter(&self) -> OwnedKeyValueListIterator {
         OwnedKeyValueListIterator::new(self)
     }
+
+    /// Snapshot the whole chain into owned, typed `Value`-s
+    ///
+    /// Drives `Capture::capture` for every key-value pair, producing a `Vec`
+    /// that owns its data and holds no references into the original stack
+    /// frame. Buffering (async) and machine-readable (JSON) drains can carry
+    /// the result across a thread boundary and emit real numbers/booleans
+    /// instead of re-rendering a borrowed `format_args!`.
+    pub fn capture(&self, record: &Record) -> result::Result<Vec<(&'static str, Value)>, ser::Error> {
+        let mut values = Vec::new();
+        for &(key, ref value) in self.iter() {
+            values.push((key, try!(value.capture(record, key))));
+        }
+        Ok(values)
+    }
 }
 
 /// Iterator over `OwnedKeyValue`-s
@@ -580,6 +765,51 @@ impl<'a> Iterator for OwnedKeyValueListIterator<'a> {
     }
 }
 
+/// Process-global runtime maximum logging level
+///
+/// `0` means "unset" and is treated as `FilterLevel::Trace` (no runtime
+/// restriction); any other value is `FilterLevel::as_usize() + 1`.
+static GLOBAL_LEVEL: AtomicUsize = AtomicUsize::new(0);
+
+/// Set the process-global maximum logging level at runtime
+///
+/// Records more verbose than `level` are discarded by the `log!` macro guard
+/// with only an atomic load, comparison and jump, without rebuilding the drain
+/// chain. This complements the compile-time `max_level_*` cargo features and is
+/// handy for eg. signal-handler-driven verbosity changes; the effective limit
+/// is always the more restrictive of the compile-time and runtime levels.
+pub fn set_global_level(level: FilterLevel) {
+    GLOBAL_LEVEL.store(level.as_usize() + 1, Ordering::Relaxed);
+}
+
+/// Get the process-global maximum logging level set by `set_global_level`
+///
+/// Defaults to `FilterLevel::Trace` (no runtime restriction) until set.
+pub fn global_level() -> FilterLevel {
+    match GLOBAL_LEVEL.load(Ordering::Relaxed) {
+        0 => FilterLevel::Trace,
+        v => FilterLevel::from_usize(v - 1).unwrap_or(FilterLevel::Trace),
+    }
+}
+
+#[allow(unknown_lints)]
+#[allow(inline_always)]
+#[inline(always)]
+#[doc(hidden)]
+/// Not an API
+///
+/// Effective maximum level: the more restrictive of the compile-time
+/// `__slog_static_max_level()` and the runtime `global_level()`.
+pub fn __slog_max_level() -> FilterLevel {
+    let stat = __slog_static_max_level();
+    let global = global_level();
+    if stat.as_usize() <= global.as_usize() {
+        stat
+    } else {
+        global
+    }
+}
+
 #[allow(unknown_lints)]
 #[allow(inline_always)]
 #[inline(always)]
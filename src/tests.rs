@@ -0,0 +1,97 @@
+use super::*;
+
+#[test]
+fn target_filter_longest_prefix_wins() {
+    let filter = TargetFilter::new(Discard,
+                                   vec![("net", FilterLevel::Info),
+                                        ("net::handshake", FilterLevel::Trace)]);
+
+    // longest matching prefix wins, regardless of rule order
+    assert_eq!(filter.level_for("net::handshake"), Some(FilterLevel::Trace));
+    assert_eq!(filter.level_for("net::handshake::v2"), Some(FilterLevel::Trace));
+    assert_eq!(filter.level_for("net::dns"), Some(FilterLevel::Info));
+    assert_eq!(filter.level_for("net"), Some(FilterLevel::Info));
+}
+
+#[test]
+fn target_filter_requires_segment_boundary() {
+    let filter = TargetFilter::new(Discard, vec![("net", FilterLevel::Info)]);
+
+    // a prefix only matches on a `::` segment boundary, not mid-identifier
+    assert_eq!(filter.level_for("network"), None);
+}
+
+#[test]
+fn target_filter_no_rule_defaults_to_admit() {
+    let filter = TargetFilter::new(Discard, vec![("net", FilterLevel::Info)]);
+
+    assert_eq!(filter.level_for("db"), None);
+}
+
+#[test]
+fn hex_renders_lowercase_two_digits_per_byte() {
+    assert_eq!(format!("{}", Hex(&[0x00, 0x0f, 0xde, 0xad, 0xbe, 0xef])),
+               "000fdeadbeef");
+}
+
+#[test]
+fn hex_summary_renders_in_full_at_the_boundary() {
+    // len == 2 * n is rendered in full, like `Hex`
+    assert_eq!(format!("{}", HexSummary(&[0xaa, 0xbb, 0xcc, 0xdd], 2)),
+               "aabbccdd");
+}
+
+#[test]
+fn hex_summary_elides_past_the_boundary() {
+    // len > 2 * n is elided to first/last n bytes plus the total length
+    assert_eq!(format!("{}", HexSummary(&[0xaa, 0xbb, 0xcc, 0xdd, 0xee], 2)),
+               "aabb..ddee (5 bytes)");
+}
+
+#[test]
+fn capture_records_the_matching_value_variant() {
+    let mut c = ValueCapture::new();
+    c.emit_bool("k", true).unwrap();
+    assert_eq!(c.value, Value::Bool(true));
+
+    c.emit_u64("k", 7).unwrap();
+    assert_eq!(c.value, Value::U64(7));
+
+    c.emit_i32("k", -3).unwrap();
+    assert_eq!(c.value, Value::I64(-3));
+
+    c.emit_f64("k", 1.5).unwrap();
+    assert_eq!(c.value, Value::F64(1.5));
+
+    c.emit_char("k", 'z').unwrap();
+    assert_eq!(c.value, Value::Char('z'));
+
+    c.emit_str("k", "hi").unwrap();
+    assert_eq!(c.value, Value::Str("hi".into()));
+
+    c.emit_unit("k").unwrap();
+    assert_eq!(c.value, Value::Unit);
+
+    c.emit_none("k").unwrap();
+    assert_eq!(c.value, Value::None);
+
+    // types without a native representation fall back to a rendered `Fmt`
+    c.emit_arguments("k", &format_args!("{}+{}", 1, 2)).unwrap();
+    assert_eq!(c.value, Value::Fmt("1+2".into()));
+}
+
+#[test]
+fn global_level_is_min_of_static_and_runtime() {
+    // `Off` is the most restrictive, so it wins the `min` and suppresses
+    // every record - even `Critical` sits above the ceiling
+    set_global_level(FilterLevel::Off);
+    assert_eq!(global_level(), FilterLevel::Off);
+    assert_eq!(__slog_max_level(), FilterLevel::Off);
+    assert!(Level::Critical.as_usize() > __slog_max_level().as_usize());
+
+    // lifting the runtime level back to the most verbose value hands control
+    // back to the compile-time maximum
+    set_global_level(FilterLevel::Trace);
+    assert_eq!(global_level(), FilterLevel::Trace);
+    assert_eq!(__slog_max_level(), __slog_static_max_level());
+}